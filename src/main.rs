@@ -1,13 +1,24 @@
 use anyhow::{Context, Result, bail};
 use chrono::{Duration, Utc};
 use clap::Parser;
+use futures::stream::{self, StreamExt};
 use log::{error, info};
+use serde::Serialize;
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions, PgSslMode};
+use std::path::PathBuf;
 use std::str::FromStr;
-use std::time::Duration as StdDuration;
+use std::sync::Arc;
+use std::time::{Duration as StdDuration, Instant};
+use tokio::signal::unix::{SignalKind, signal};
+use tokio::sync::RwLock;
 
 mod module;
-use module::pg::is_valid_tname;
+use module::config::{load_file_config, resolve_table_rules};
+use module::pg::{PartitionBound, is_valid_qualified_tname, is_valid_tname, parse_partition_bound};
+
+/// Connections available in the pool; also the ceiling for `--concurrency`
+/// so it can't starve itself waiting on acquires.
+const MAX_CONNECTIONS: u32 = 5;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -28,18 +39,78 @@ struct Args {
     #[arg(long)]
     db_name: String,
 
-    /// Table(s) in a table[:`timestampColumn=created_at`[:days=0]] format
+    /// Table(s) in a table[:`timestampColumn=created_at`[:days=0][:mode=delete][:archive]] format
     #[arg(long = "table")]
     tables: Vec<String>,
 
+    /// TOML/YAML config file defining the connection and table rules.
+    /// When set, `--table` is ignored and `SIGHUP` reloads this file
+    /// in place, re-parsing it into the active rule set for the next
+    /// cleanup pass.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
     /// Optional batch size for cleanup
     #[arg(long, default_value_t = 1000)]
     batch: i64,
 
+    /// Default to the partition-drop fast path for every table instead of
+    /// the batched ctid DELETE. Can still be overridden per table via the
+    /// `mode` field in `--table`/`--config`.
+    #[arg(long)]
+    drop_expired_partitions: bool,
+
+    /// Archive expired rows into `<table><suffix>` before deleting them,
+    /// for tables that don't set an explicit `archive=schema.table` in
+    /// their spec.
+    #[arg(long)]
+    archive_suffix: Option<String>,
+
+    /// Auto-create the archive table via `CREATE TABLE IF NOT EXISTS
+    /// <archive> (LIKE "<table>" INCLUDING DEFAULTS)` if it doesn't exist.
+    #[arg(long)]
+    archive_auto_create: bool,
+
+    /// Clean up this many tables in parallel instead of strictly
+    /// sequentially. Clamped to the connection pool's `max_connections`.
+    #[arg(long, default_value_t = 1)]
+    concurrency: usize,
+
+    /// Run a maintenance VACUUM on a table after its cleanup pass:
+    /// `vacuum`, `vacuum-analyze`, or `vacuum-analyze-freeze`.
+    #[arg(long)]
+    post_vacuum: Option<String>,
+
+    /// Timeout for the post-cleanup VACUUM, separate from `--timeout`
+    /// since a full-table VACUUM typically runs much longer than a batch.
+    #[arg(long, default_value = "300s", value_parser = parse_duration)]
+    vacuum_timeout: StdDuration,
+
+    /// Run as a long-lived daemon, re-running the full cleanup pass on
+    /// this cadence (e.g. `1h`, `30m`) instead of exiting after one pass.
+    /// `SIGTERM`/`SIGINT` trigger a graceful shutdown after the in-flight
+    /// pass finishes.
+    #[arg(long, value_parser = parse_duration)]
+    interval: Option<StdDuration>,
+
     /// Single db operation timeout in seconds
     #[arg(long, default_value = "60s", value_parser = parse_duration)]
     timeout: StdDuration,
 
+    /// Path to a PEM-encoded root CA certificate to trust instead of (or
+    /// in addition to) the OS trust store. Required to run `verify-ca`/
+    /// `verify-full` against a server using a private CA.
+    #[arg(long)]
+    ssl_root_cert: Option<PathBuf>,
+
+    /// Path to a PEM-encoded client certificate for mutual TLS.
+    #[arg(long, requires = "ssl_client_key")]
+    ssl_client_cert: Option<PathBuf>,
+
+    /// Path to the PEM-encoded private key for `--ssl-client-cert`.
+    #[arg(long, requires = "ssl_client_cert")]
+    ssl_client_key: Option<PathBuf>,
+
     /// Database username
     #[arg(long, env = "DB_USERNAME")]
     db_username: String,
@@ -47,28 +118,128 @@ struct Args {
     /// Database password
     #[arg(long, env = "DB_PASSWORD")]
     db_password: String,
+
+    /// Report how many rows each table would delete (`SELECT count(*)` over
+    /// the same cutoff) without deleting, archiving, dropping partitions,
+    /// or running `--post-vacuum`.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Emit a JSON line per table after its cleanup pass (rows matched,
+    /// batches executed, elapsed time, and any error), for log pipelines
+    /// and monitoring instead of free-form log text.
+    #[arg(long)]
+    json: bool,
 }
 
-fn parse_duration(s: &str) -> Result<StdDuration, String> {
-    let Some(s) = s.strip_suffix('s') else {
-        return Err("Duration must end with 's' (e.g., '60s')".to_string());
+pub(crate) fn parse_duration(s: &str) -> Result<StdDuration, String> {
+    let (value, multiplier) = if let Some(v) = s.strip_suffix('h') {
+        (v, 3600)
+    } else if let Some(v) = s.strip_suffix('m') {
+        (v, 60)
+    } else if let Some(v) = s.strip_suffix('s') {
+        (v, 1)
+    } else {
+        return Err("Duration must end with 's', 'm', or 'h' (e.g., '60s', '5m', '1h')".to_string());
     };
 
-    s.parse::<u64>()
-        .map(StdDuration::from_secs)
+    value
+        .parse::<u64>()
+        .map(|n| StdDuration::from_secs(n * multiplier))
         .map_err(|e| format!("Invalid duration: {e}"))
 }
 
-struct TableConfig {
-    name: String,
-    timestamp_column: String,
-    days: i64,
+/// Which maintenance command `--post-vacuum` runs after a table's cleanup
+/// pass finishes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum VacuumMode {
+    Vacuum,
+    VacuumAnalyze,
+    VacuumAnalyzeFreeze,
 }
 
-impl FromStr for TableConfig {
+impl VacuumMode {
+    fn as_sql(self) -> &'static str {
+        match self {
+            VacuumMode::Vacuum => "VACUUM",
+            VacuumMode::VacuumAnalyze => "VACUUM (ANALYZE)",
+            VacuumMode::VacuumAnalyzeFreeze => "VACUUM (ANALYZE, FREEZE)",
+        }
+    }
+}
+
+impl FromStr for VacuumMode {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "vacuum" => Ok(VacuumMode::Vacuum),
+            "vacuum-analyze" => Ok(VacuumMode::VacuumAnalyze),
+            "vacuum-analyze-freeze" => Ok(VacuumMode::VacuumAnalyzeFreeze),
+            _ => bail!("Unsupported post-vacuum mode: {s}"),
+        }
+    }
+}
+
+/// How `cleanup_table` removes expired rows from a given table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub(crate) enum CleanupMode {
+    /// Batched `DELETE ... WHERE ctid IN (...)`.
+    #[default]
+    Delete,
+    /// Drop whole expired child partitions of a `RANGE`-partitioned parent,
+    /// falling back to `Delete` only for the boundary partition.
+    Partition,
+}
+
+impl CleanupMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            CleanupMode::Delete => "delete",
+            CleanupMode::Partition => "partition",
+        }
+    }
+}
+
+impl FromStr for CleanupMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "delete" => Ok(CleanupMode::Delete),
+            "partition" => Ok(CleanupMode::Partition),
+            _ => bail!("Unsupported cleanup mode: {s}"),
+        }
+    }
+}
+
+/// Per-run defaults applied to a table spec when it doesn't override them
+/// itself, shared between the `--table` CLI parser and the config-file
+/// parser.
+#[derive(Clone, Default)]
+pub(crate) struct TableConfigDefaults {
+    pub(crate) mode: CleanupMode,
+    pub(crate) archive_suffix: Option<String>,
+    pub(crate) batch: i64,
+    pub(crate) timeout: StdDuration,
+}
+
+#[derive(Clone)]
+pub(crate) struct TableConfig {
+    pub(crate) name: String,
+    pub(crate) timestamp_column: String,
+    pub(crate) days: i64,
+    pub(crate) mode: CleanupMode,
+    pub(crate) archive: Option<String>,
+    /// Per-table override of `--batch`, so an operator can tune batch size
+    /// per table in a `--config` file instead of one size for the whole run.
+    pub(crate) batch: i64,
+    /// Per-table override of `--timeout`, for the same reason.
+    pub(crate) timeout: StdDuration,
+}
+
+impl TableConfig {
+    fn from_str_with_defaults(s: &str, defaults: &TableConfigDefaults) -> Result<Self> {
         let parts: Vec<&str> = s.split(':').collect();
 
         let name = parts[0].to_string();
@@ -96,14 +267,51 @@ impl FromStr for TableConfig {
             .context("Failed to parse days")?
             .unwrap_or(0);
 
+        let mode = parts
+            .get(3)
+            .filter(|s| !s.is_empty())
+            .map(|s| CleanupMode::from_str(s))
+            .transpose()?
+            .unwrap_or(defaults.mode);
+
+        let archive = parts
+            .get(4)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                if is_valid_qualified_tname(s) {
+                    Ok(s.to_string())
+                } else {
+                    bail!("Invalid archive target: {s}")
+                }
+            })
+            .transpose()?
+            .or_else(|| {
+                defaults
+                    .archive_suffix
+                    .as_ref()
+                    .map(|suffix| format!("{name}{suffix}"))
+            });
+
         Ok(TableConfig {
             name,
             timestamp_column,
             days,
+            mode,
+            archive,
+            batch: defaults.batch,
+            timeout: defaults.timeout,
         })
     }
 }
 
+impl FromStr for TableConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        TableConfig::from_str_with_defaults(s, &TableConfigDefaults::default())
+    }
+}
+
 fn parse_ssl_mode(mode: &str) -> Result<PgSslMode> {
     match mode {
         "disable" => Ok(PgSslMode::Disable),
@@ -114,6 +322,21 @@ fn parse_ssl_mode(mode: &str) -> Result<PgSslMode> {
     }
 }
 
+/// Fail fast with a clear error if a TLS file argument doesn't exist or
+/// isn't readable, rather than letting sqlx surface an opaque connection
+/// error later.
+fn validate_readable_file(flag: &str, path: &std::path::Path) -> Result<()> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("{flag} {} is not a readable file", path.display()))?;
+
+    // Opening a directory succeeds on Unix, but reading it later does not.
+    if !file.metadata().is_ok_and(|m| m.is_file()) {
+        bail!("{flag} {} is not a readable file", path.display());
+    }
+
+    Ok(())
+}
+
 async fn with_timeout<F, T, E>(timeout: StdDuration, fut: F) -> Result<T>
 where
     F: Future<Output = Result<T, E>>,
@@ -129,46 +352,162 @@ where
     }
 }
 
-async fn cleanup_table(
+/// Quote each `.`-separated segment of a (possibly schema-qualified)
+/// identifier on its own, e.g. `audit.events` -> `"audit"."events"`.
+fn quote_identifier_path(name: &str) -> String {
+    name.split('.')
+        .map(|part| format!("\"{part}\""))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Run `mode` on `table` outside of a transaction block (VACUUM cannot
+/// run inside one), returning the bytes reclaimed so the caller can log
+/// the space impact alongside the rows deleted.
+async fn vacuum_table(
     pool: &sqlx::PgPool,
-    config: &TableConfig,
+    table: &str,
+    mode: VacuumMode,
+    timeout: StdDuration,
+) -> Result<i64> {
+    let size_before: i64 = with_timeout(
+        timeout,
+        sqlx::query_scalar("SELECT pg_total_relation_size($1::regclass)")
+            .bind(table)
+            .fetch_one(pool),
+    )
+    .await?;
+
+    with_timeout(
+        timeout,
+        sqlx::query(&format!(r#"{} "{table}""#, mode.as_sql())).execute(pool),
+    )
+    .await?;
+
+    let size_after: i64 = with_timeout(
+        timeout,
+        sqlx::query_scalar("SELECT pg_total_relation_size($1::regclass)")
+            .bind(table)
+            .fetch_one(pool),
+    )
+    .await?;
+
+    Ok(size_before - size_after)
+}
+
+/// Rows matched and batches executed by a single table's cleanup, whether
+/// it actually deleted anything (`--dry-run` just counts, so `batches`
+/// stays 0) or ran the partition-drop fast path (dropping a whole
+/// partition adds no batch; only the row-level delete of the boundary
+/// partition, or of a table with no partitions, adds to `batches`).
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct CleanupOutcome {
+    pub(crate) rows_matched: u64,
+    pub(crate) batches: u32,
+}
+
+/// Batched `DELETE ... WHERE ctid IN (...)` against a single named table,
+/// shared by the plain delete mode and the partition mode's boundary
+/// partition fallback. When `archive` is set, each batch is archived into
+/// it via a writable CTE so the insert and the delete share the exact
+/// same row set and commit atomically together. When `dry_run` is set, no
+/// row is touched (and the archive table isn't even created); instead a
+/// single `SELECT count(*)` over the same predicate reports how many rows
+/// would have been deleted.
+#[allow(clippy::too_many_arguments)]
+async fn delete_expired_rows(
+    pool: &sqlx::PgPool,
+    table: &str,
+    column: &str,
+    archive: Option<&str>,
+    auto_create_archive: bool,
+    dry_run: bool,
+    cutoff: chrono::DateTime<Utc>,
     batch_size: i64,
     timeout: StdDuration,
-) -> Result<()> {
-    info!(
-        "Cleaning up table {} by column {} for records older than {} days (batch={})",
-        config.name, config.timestamp_column, config.days, batch_size
-    );
+) -> Result<CleanupOutcome> {
+    // `table` may be a bare name or a partition's `schema.relname`, so every
+    // reference to it in SQL must go through this identifier, never a raw
+    // `"{table}"` format (which would quote a dot-qualified name as one
+    // broken identifier instead of resolving it via `search_path`).
+    let table_ident = quote_identifier_path(table);
 
-    let cutoff = Utc::now() - Duration::days(config.days);
+    if dry_run {
+        let rows_matched: i64 = with_timeout(
+            timeout,
+            sqlx::query_scalar(&format!(r#"SELECT count(*) FROM {table_ident} WHERE "{column}" < $1"#))
+                .bind(cutoff)
+                .fetch_one(pool),
+        )
+        .await?;
+
+        info!("[dry-run] Would delete {rows_matched} rows from {table}");
+
+        return Ok(CleanupOutcome {
+            rows_matched: rows_matched as u64,
+            batches: 0,
+        });
+    }
+
+    if auto_create_archive {
+        if let Some(archive_table) = archive {
+            let archive_ident = quote_identifier_path(archive_table);
+
+            with_timeout(
+                timeout,
+                sqlx::query(&format!(
+                    "CREATE TABLE IF NOT EXISTS {archive_ident} (LIKE {table_ident} INCLUDING DEFAULTS)"
+                ))
+                .execute(pool),
+            )
+            .await?;
+        }
+    }
 
     // Build SQL query once with identifiers (which must use format!)
     // Then bind values using proper parameterization
-    let stmt = if batch_size > 0 {
-        format!(
-            r#"DELETE FROM "{table}"
+    let stmt = match (archive, batch_size > 0) {
+        (Some(archive_table), has_limit) => {
+            let archive_ident = quote_identifier_path(archive_table);
+            let limit_clause = if has_limit { "LIMIT $2" } else { "" };
+
+            format!(
+                r#"WITH expired AS (
+                       SELECT ctid FROM {table_ident}
+                       WHERE "{column}" < $1
+                       ORDER BY "{column}"
+                       {limit_clause}
+                   ), archived AS (
+                       INSERT INTO {archive_ident}
+                       SELECT t.* FROM {table_ident} t JOIN expired e ON t.ctid = e.ctid
+                       RETURNING 1
+                   )
+                   DELETE FROM {table_ident}
+                   WHERE ctid IN (SELECT ctid FROM expired)"#
+            )
+        }
+        (None, true) => format!(
+            r#"DELETE FROM {table_ident}
                WHERE ctid IN (
-                   SELECT ctid FROM "{table}"
-                   WHERE "{col}" < $1
-                   ORDER BY "{col}"
+                   SELECT ctid FROM {table_ident}
+                   WHERE "{column}" < $1
+                   ORDER BY "{column}"
                    LIMIT $2
-               )"#,
-            table = config.name,
-            col = config.timestamp_column
-        )
-    } else {
-        format!(
-            r#"DELETE FROM "{table}"
+               )"#
+        ),
+        (None, false) => format!(
+            r#"DELETE FROM {table_ident}
                WHERE ctid IN (
-                   SELECT ctid FROM "{table}"
-                   WHERE "{col}" < $1
-                   ORDER BY "{col}"
-               )"#,
-            table = config.name,
-            col = config.timestamp_column
-        )
+                   SELECT ctid FROM {table_ident}
+                   WHERE "{column}" < $1
+                   ORDER BY "{column}"
+               )"#
+        ),
     };
 
+    let mut total_deleted: u64 = 0;
+    let mut batches: u32 = 0;
+
     loop {
         let mut tx = with_timeout(timeout, pool.begin()).await?;
 
@@ -186,43 +525,451 @@ async fn cleanup_table(
                 let rows_affected = res.rows_affected();
 
                 with_timeout(timeout, tx.commit()).await?;
+                batches += 1;
 
                 if rows_affected == 0 {
-                    info!(
-                        "No more rows to delete in table {}. Moving to next table.",
-                        config.name
-                    );
+                    info!("No more rows to delete in table {table}. Moving to next table.");
                     break;
                 }
 
-                info!("Deleted {} rows from {}", rows_affected, config.name);
+                total_deleted += rows_affected;
+                info!("Deleted {rows_affected} rows from {table}");
 
                 if batch_size == 0 {
                     break;
                 }
             }
             Err(e) => {
-                error!("Failed to execute query for table {}: {}", config.name, e);
+                error!("Failed to execute query for table {table}: {e}");
                 let _ = tx.rollback().await;
                 return Err(e);
             }
         }
     }
 
-    Ok(())
+    Ok(CleanupOutcome {
+        rows_matched: total_deleted,
+        batches,
+    })
+}
+
+/// One table's result from a cleanup pass, emitted as a JSON line when
+/// `--json` is set so the tool can feed log pipelines and monitoring
+/// instead of only free-form `info!`/`error!` text.
+#[derive(Debug, Serialize)]
+struct TableRunSummary<'a> {
+    table: &'a str,
+    mode: &'static str,
+    dry_run: bool,
+    rows_matched: u64,
+    batches: u32,
+    elapsed_ms: u128,
+    error: Option<String>,
+}
+
+/// Run one cleanup pass over `tables`, up to `concurrency` at a time.
+/// Each table's failure is logged and doesn't stop the rest; the pass
+/// always runs to completion so a shutdown signal never interrupts an
+/// in-flight batch transaction.
+#[allow(clippy::too_many_arguments)]
+async fn run_cleanup_pass(
+    pool: &sqlx::PgPool,
+    tables: &[TableConfig],
+    auto_create_archive: bool,
+    dry_run: bool,
+    post_vacuum: Option<VacuumMode>,
+    vacuum_timeout: StdDuration,
+    concurrency: usize,
+    json: bool,
+) {
+    stream::iter(tables)
+        .map(|config| async move {
+            let started = Instant::now();
+
+            let result = cleanup_table(pool, config, auto_create_archive, dry_run, post_vacuum, vacuum_timeout).await;
+
+            let elapsed_ms = started.elapsed().as_millis();
+
+            let (rows_matched, batches, error) = match &result {
+                Ok(outcome) => (outcome.rows_matched, outcome.batches, None),
+                Err(e) => {
+                    error!("Failed to cleanup table {}: {}", config.name, e);
+                    (0, 0, Some(e.to_string()))
+                }
+            };
+
+            if json {
+                let summary = TableRunSummary {
+                    table: &config.name,
+                    mode: config.mode.as_str(),
+                    dry_run,
+                    rows_matched,
+                    batches,
+                    elapsed_ms,
+                    error,
+                };
+
+                match serde_json::to_string(&summary) {
+                    Ok(line) => println!("{line}"),
+                    Err(e) => error!("Failed to serialize summary for table {}: {e}", config.name),
+                }
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect::<Vec<()>>()
+        .await;
+}
+
+async fn cleanup_table(
+    pool: &sqlx::PgPool,
+    config: &TableConfig,
+    auto_create_archive: bool,
+    dry_run: bool,
+    post_vacuum: Option<VacuumMode>,
+    vacuum_timeout: StdDuration,
+) -> Result<CleanupOutcome> {
+    info!(
+        "Cleaning up table {} by column {} for records older than {} days (batch={})",
+        config.name, config.timestamp_column, config.days, config.batch
+    );
+
+    let cutoff = Utc::now() - Duration::days(config.days);
+
+    let outcome = match config.mode {
+        CleanupMode::Delete => {
+            delete_expired_rows(
+                pool,
+                &config.name,
+                &config.timestamp_column,
+                config.archive.as_deref(),
+                auto_create_archive,
+                dry_run,
+                cutoff,
+                config.batch,
+                config.timeout,
+            )
+            .await?
+        }
+        CleanupMode::Partition => {
+            drop_expired_partitions(
+                pool,
+                config,
+                auto_create_archive,
+                dry_run,
+                cutoff,
+                config.batch,
+                config.timeout,
+            )
+            .await?
+        }
+    };
+
+    // A dry run only counts rows; running VACUUM would be a real side
+    // effect on an otherwise read-only pass, so it's skipped entirely.
+    if !dry_run {
+        if let Some(mode) = post_vacuum {
+            match vacuum_table(pool, &config.name, mode, vacuum_timeout).await {
+                Ok(reclaimed_bytes) => info!(
+                    "Post-cleanup vacuum of {}: deleted {} rows, reclaimed {} bytes",
+                    config.name, outcome.rows_matched, reclaimed_bytes
+                ),
+                Err(e) => error!("Post-cleanup vacuum of {} failed: {e}", config.name),
+            }
+        }
+    }
+
+    Ok(outcome)
+}
+
+struct PartitionChild {
+    /// `schema.relname`, not just `relname` — partitions can live in a
+    /// different schema than the one `search_path` would resolve a bare
+    /// name against, so every reference to this name must go through
+    /// [`quote_identifier_path`] rather than a raw `"{name}"` format.
+    name: String,
+    bound: PartitionBound,
+}
+
+async fn list_partitions(
+    pool: &sqlx::PgPool,
+    parent: &str,
+    timeout: StdDuration,
+) -> Result<Vec<PartitionChild>> {
+    let rows = with_timeout(
+        timeout,
+        sqlx::query_as::<_, (String, String, Option<String>)>(
+            r#"SELECT n.nspname, c.relname, pg_get_expr(c.relpartbound, c.oid)
+               FROM pg_inherits i
+               JOIN pg_class c ON c.oid = i.inhrelid
+               JOIN pg_namespace n ON n.oid = c.relnamespace
+               WHERE i.inhparent = $1::regclass"#,
+        )
+        .bind(parent)
+        .fetch_all(pool),
+    )
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(schema, relname, bound_expr)| {
+            let bound = bound_expr
+                .map(|expr| parse_partition_bound(&expr))
+                .unwrap_or(PartitionBound::Unrecognized);
+
+            PartitionChild {
+                name: format!("{schema}.{relname}"),
+                bound,
+            }
+        })
+        .collect())
+}
+
+/// Drop whole expired partitions of a `RANGE`-partitioned parent instead
+/// of deleting rows one by one. The partition currently straddling
+/// `cutoff` is never dropped; it's cleaned up with the same batched
+/// `DELETE` as non-partitioned tables, and the default partition (if any)
+/// is left untouched entirely. Every expired partition's row count is
+/// added to `rows_matched` via a `SELECT count(*)` taken before it's
+/// touched, whether `dry_run` stops there or a real run goes on to drop
+/// (and possibly archive) it — so the reported total is the same either
+/// way, and a real run's metrics aren't an undercount of what `--dry-run`
+/// predicted. When `config.archive` is set, a whole expired partition is archived
+/// into it (`INSERT INTO <archive> SELECT * FROM <partition>`) in the
+/// same transaction as the `DROP TABLE`, so the two either both commit or
+/// neither does — dropping a partition is still a delete, and request 4's
+/// "archive before deleting" applies to it exactly as it does to a
+/// batched `DELETE`.
+#[allow(clippy::too_many_arguments)]
+async fn drop_expired_partitions(
+    pool: &sqlx::PgPool,
+    config: &TableConfig,
+    auto_create_archive: bool,
+    dry_run: bool,
+    cutoff: chrono::DateTime<Utc>,
+    batch_size: i64,
+    timeout: StdDuration,
+) -> Result<CleanupOutcome> {
+    let children = list_partitions(pool, &config.name, timeout).await?;
+
+    if children.is_empty() {
+        info!(
+            "Table {} has no partitions; falling back to batched delete",
+            config.name
+        );
+
+        return delete_expired_rows(
+            pool,
+            &config.name,
+            &config.timestamp_column,
+            config.archive.as_deref(),
+            auto_create_archive,
+            dry_run,
+            cutoff,
+            batch_size,
+            timeout,
+        )
+        .await;
+    }
+
+    let mut ranged: Vec<(String, chrono::DateTime<Utc>)> = Vec::new();
+
+    for child in children {
+        match child.bound {
+            PartitionBound::Default => {
+                info!("Skipping default partition {}", child.name);
+            }
+            PartitionBound::Unrecognized => {
+                info!(
+                    "Could not parse partition bound for {}; leaving it untouched",
+                    child.name
+                );
+            }
+            PartitionBound::Range(upper_bound) => ranged.push((child.name, upper_bound)),
+        }
+    }
+
+    ranged.sort_by_key(|(_, upper_bound)| *upper_bound);
+
+    let mut boundary_handled = false;
+    let mut outcome = CleanupOutcome::default();
+
+    for (name, upper_bound) in ranged {
+        let name_ident = quote_identifier_path(&name);
+
+        if upper_bound <= cutoff {
+            // Counted up front, before any drop, so a real run's `rows_matched`
+            // reports the same number a `--dry-run` over the same partition
+            // would — the fast path still removes these rows, it just does it
+            // by dropping the whole partition instead of a row-level DELETE.
+            let rows_matched: i64 = with_timeout(
+                timeout,
+                sqlx::query_scalar(&format!("SELECT count(*) FROM {name_ident}")).fetch_one(pool),
+            )
+            .await?;
+
+            if dry_run {
+                info!(
+                    "[dry-run] Would drop expired partition {name} (upper bound {upper_bound}, {rows_matched} rows)"
+                );
+            } else if let Some(archive_table) = config.archive.as_deref() {
+                let archive_ident = quote_identifier_path(archive_table);
+
+                if auto_create_archive {
+                    with_timeout(
+                        timeout,
+                        sqlx::query(&format!(
+                            "CREATE TABLE IF NOT EXISTS {archive_ident} (LIKE {name_ident} INCLUDING DEFAULTS)"
+                        ))
+                        .execute(pool),
+                    )
+                    .await?;
+                }
+
+                info!("Archiving expired partition {name} ({rows_matched} rows) into {archive_table} before dropping it");
+
+                let mut tx = with_timeout(timeout, pool.begin()).await?;
+
+                with_timeout(
+                    timeout,
+                    sqlx::query(&format!("INSERT INTO {archive_ident} SELECT * FROM {name_ident}")).execute(&mut *tx),
+                )
+                .await?;
+
+                with_timeout(timeout, sqlx::query(&format!("DROP TABLE {name_ident}")).execute(&mut *tx)).await?;
+
+                with_timeout(timeout, tx.commit()).await?;
+            } else {
+                info!("Dropping expired partition {name} (upper bound {upper_bound}, {rows_matched} rows)");
+
+                with_timeout(timeout, sqlx::query(&format!("DROP TABLE {name_ident}")).execute(pool)).await?;
+            }
+
+            outcome.rows_matched += rows_matched as u64;
+        } else if !boundary_handled {
+            // The first partition whose range extends past cutoff is the
+            // one straddling it; it keeps some live rows, so fall back to
+            // a row-level delete instead of dropping it outright.
+            boundary_handled = true;
+
+            info!("Partition {name} straddles the cutoff; batch-deleting expired rows from it");
+
+            let boundary_outcome = delete_expired_rows(
+                pool,
+                &name,
+                &config.timestamp_column,
+                config.archive.as_deref(),
+                auto_create_archive,
+                dry_run,
+                cutoff,
+                batch_size,
+                timeout,
+            )
+            .await?;
+
+            outcome.rows_matched += boundary_outcome.rows_matched;
+            outcome.batches += boundary_outcome.batches;
+        }
+    }
+
+    Ok(outcome)
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
 
-    let args = Args::parse();
+    let mut args = Args::parse();
 
-    if args.tables.is_empty() {
-        bail!("At least one --table argument is required");
+    if args.config.is_none() && args.tables.is_empty() {
+        bail!("At least one --table argument is required (or pass --config)");
     }
 
-    let opts = PgConnectOptions::new()
+    let defaults = TableConfigDefaults {
+        mode: if args.drop_expired_partitions {
+            CleanupMode::Partition
+        } else {
+            CleanupMode::Delete
+        },
+        archive_suffix: args.archive_suffix.clone(),
+        batch: args.batch,
+        timeout: args.timeout,
+    };
+
+    let initial_tables = match &args.config {
+        Some(path) => {
+            let file_config = load_file_config(path)
+                .with_context(|| format!("Failed to load config file {}", path.display()))?;
+
+            if let Some(host) = &file_config.connection.host {
+                args.host = host.clone();
+            }
+            if let Some(port) = file_config.connection.port {
+                args.port = port;
+            }
+            if let Some(db_name) = &file_config.connection.db_name {
+                args.db_name = db_name.clone();
+            }
+            if let Some(ssl_mode) = &file_config.connection.ssl_mode {
+                args.ssl_mode = ssl_mode.clone();
+            }
+
+            resolve_table_rules(&file_config, &defaults)
+        }
+        None => args
+            .tables
+            .iter()
+            .filter_map(
+                |table_str| match TableConfig::from_str_with_defaults(table_str, &defaults) {
+                    Ok(c) => Some(c),
+                    Err(e) => {
+                        error!("Invalid table format {table_str}: {e}");
+                        None
+                    }
+                },
+            )
+            .collect(),
+    };
+
+    let active_tables = Arc::new(RwLock::new(initial_tables));
+
+    if let Some(config_path) = args.config.clone() {
+        let reload_tables = active_tables.clone();
+        let reload_defaults = defaults.clone();
+
+        let mut sighup = signal(SignalKind::hangup()).context("Failed to register SIGHUP handler")?;
+
+        tokio::spawn(async move {
+            loop {
+                sighup.recv().await;
+
+                match load_file_config(&config_path) {
+                    Ok(file_config) => {
+                        let tables = resolve_table_rules(&file_config, &reload_defaults);
+                        *reload_tables.write().await = tables;
+                        info!("Reloaded configuration from {}", config_path.display());
+                    }
+                    Err(e) => {
+                        error!(
+                            "Failed to reload config {}: {e}. Keeping previous configuration",
+                            config_path.display()
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    if let Some(path) = &args.ssl_root_cert {
+        validate_readable_file("--ssl-root-cert", path)?;
+    }
+    if let Some(path) = &args.ssl_client_cert {
+        validate_readable_file("--ssl-client-cert", path)?;
+    }
+    if let Some(path) = &args.ssl_client_key {
+        validate_readable_file("--ssl-client-key", path)?;
+    }
+
+    let mut opts = PgConnectOptions::new()
         .host(&args.host)
         .port(args.port)
         .username(&args.db_username)
@@ -230,8 +977,18 @@ async fn main() -> Result<()> {
         .database(&args.db_name)
         .ssl_mode(parse_ssl_mode(&args.ssl_mode)?);
 
+    if let Some(path) = &args.ssl_root_cert {
+        opts = opts.ssl_root_cert(path);
+    }
+    if let Some(path) = &args.ssl_client_cert {
+        opts = opts.ssl_client_cert(path);
+    }
+    if let Some(path) = &args.ssl_client_key {
+        opts = opts.ssl_client_key(path);
+    }
+
     let pool = PgPoolOptions::new()
-        .max_connections(5)
+        .max_connections(MAX_CONNECTIONS)
         .acquire_timeout(StdDuration::from_secs(10))
         .connect_with(opts)
         .await
@@ -239,19 +996,127 @@ async fn main() -> Result<()> {
 
     info!("Connected to the database successfully");
 
-    for table_str in &args.tables {
-        let config = match TableConfig::from_str(table_str) {
-            Ok(c) => c,
-            Err(e) => {
-                error!("Invalid table format {table_str}: {e}");
-                continue;
+    let post_vacuum = args
+        .post_vacuum
+        .as_deref()
+        .map(VacuumMode::from_str)
+        .transpose()?;
+
+    let concurrency = args.concurrency.clamp(1, MAX_CONNECTIONS as usize);
+
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
+
+    {
+        let shutdown_tx = shutdown_tx.clone();
+        let mut sigterm = signal(SignalKind::terminate()).context("Failed to register SIGTERM handler")?;
+
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
             }
+
+            info!("Received shutdown signal; exiting after the current cleanup pass");
+            let _ = shutdown_tx.send(true);
+        });
+    }
+
+    loop {
+        let tables = active_tables.read().await.clone();
+
+        run_cleanup_pass(
+            &pool,
+            &tables,
+            args.archive_auto_create,
+            args.dry_run,
+            post_vacuum,
+            args.vacuum_timeout,
+            concurrency,
+            args.json,
+        )
+        .await;
+
+        if *shutdown_rx.borrow() {
+            break;
+        }
+
+        let Some(interval) = args.interval else {
+            break;
         };
 
-        if let Err(e) = cleanup_table(&pool, &config, args.batch, args.timeout).await {
-            error!("Failed to cleanup table {}: {}", config.name, e);
+        info!("Cleanup pass complete. Sleeping for {interval:?} before the next pass.");
+
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = shutdown_rx.changed() => {}
+        }
+
+        if *shutdown_rx.borrow() {
+            break;
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_accepts_seconds_minutes_and_hours() {
+        assert_eq!(parse_duration("45s").unwrap(), StdDuration::from_secs(45));
+        assert_eq!(parse_duration("5m").unwrap(), StdDuration::from_secs(300));
+        assert_eq!(parse_duration("2h").unwrap(), StdDuration::from_secs(7200));
+    }
+
+    #[test]
+    fn parse_duration_rejects_missing_or_unknown_suffix() {
+        assert!(parse_duration("60").is_err());
+        assert!(parse_duration("60d").is_err());
+    }
+
+    #[test]
+    fn cleanup_mode_from_str_round_trips_through_as_str() {
+        assert_eq!(CleanupMode::from_str("delete").unwrap(), CleanupMode::Delete);
+        assert_eq!(CleanupMode::from_str("partition").unwrap(), CleanupMode::Partition);
+        assert!(CleanupMode::from_str("bogus").is_err());
+        assert_eq!(CleanupMode::Delete.as_str(), "delete");
+        assert_eq!(CleanupMode::Partition.as_str(), "partition");
+    }
+
+    #[test]
+    fn vacuum_mode_from_str_maps_to_expected_sql() {
+        assert_eq!(VacuumMode::from_str("vacuum").unwrap().as_sql(), "VACUUM");
+        assert_eq!(VacuumMode::from_str("vacuum-analyze").unwrap().as_sql(), "VACUUM (ANALYZE)");
+        assert_eq!(
+            VacuumMode::from_str("vacuum-analyze-freeze").unwrap().as_sql(),
+            "VACUUM (ANALYZE, FREEZE)"
+        );
+        assert!(VacuumMode::from_str("bogus").is_err());
+    }
+
+    #[test]
+    fn table_config_from_str_applies_defaults() {
+        let config = TableConfig::from_str("events").unwrap();
+        assert_eq!(config.timestamp_column, "created_at");
+        assert_eq!(config.days, 0);
+        assert_eq!(config.mode, CleanupMode::Delete);
+        assert_eq!(config.archive, None);
+    }
+
+    #[test]
+    fn table_config_from_str_with_defaults_falls_back_to_archive_suffix() {
+        let defaults = TableConfigDefaults {
+            mode: CleanupMode::Partition,
+            archive_suffix: Some("_archive".to_string()),
+            batch: 500,
+            timeout: StdDuration::from_secs(30),
+        };
+        let config = TableConfig::from_str_with_defaults("events:created_at:30", &defaults).unwrap();
+        assert_eq!(config.mode, CleanupMode::Partition);
+        assert_eq!(config.archive.as_deref(), Some("events_archive"));
+        assert_eq!(config.batch, 500);
+        assert_eq!(config.timeout, StdDuration::from_secs(30));
+    }
+}
@@ -0,0 +1,132 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use log::error;
+use serde::Deserialize;
+
+use crate::module::pg::{is_valid_qualified_tname, is_valid_tname};
+use crate::{CleanupMode, TableConfig, TableConfigDefaults, parse_duration};
+
+/// Connection fields a config file may override. Anything left unset keeps
+/// the value supplied on the command line.
+#[derive(Debug, Default, Deserialize)]
+pub struct ConnectionSpec {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub db_name: Option<String>,
+    pub ssl_mode: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TableRuleSpec {
+    pub name: String,
+    #[serde(default = "default_timestamp_column")]
+    pub timestamp_column: String,
+    #[serde(default)]
+    pub days: i64,
+    /// `"delete"` or `"partition"`. Falls back to the `--drop-expired-partitions`
+    /// flag's default when unset.
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// `schema.table` (or bare `table`) to archive expired rows into
+    /// before deleting them. Falls back to `--archive-suffix` when unset.
+    #[serde(default)]
+    pub archive: Option<String>,
+    /// Per-table batch size, overriding `--batch` for this table only.
+    #[serde(default)]
+    pub batch: Option<i64>,
+    /// Per-table operation timeout (e.g. `"30s"`, `"5m"`), overriding
+    /// `--timeout` for this table only.
+    #[serde(default)]
+    pub timeout: Option<String>,
+}
+
+fn default_timestamp_column() -> String {
+    "created_at".to_string()
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FileConfig {
+    #[serde(default)]
+    pub connection: ConnectionSpec,
+    #[serde(default)]
+    pub tables: Vec<TableRuleSpec>,
+}
+
+/// Load a config file, choosing a parser by extension (`.yaml`/`.yml` or
+/// `.toml`, defaulting to TOML for anything else).
+pub fn load_file_config(path: &Path) -> Result<FileConfig> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&raw)
+            .with_context(|| format!("Failed to parse YAML config {}", path.display())),
+        _ => toml::from_str(&raw)
+            .with_context(|| format!("Failed to parse TOML config {}", path.display())),
+    }
+}
+
+fn to_table_config(spec: &TableRuleSpec, defaults: &TableConfigDefaults) -> Result<TableConfig> {
+    if !is_valid_tname(&spec.name) {
+        bail!("Invalid table name: {}", spec.name);
+    }
+
+    if !is_valid_tname(&spec.timestamp_column) {
+        bail!("Invalid timestamp column: {}", spec.timestamp_column);
+    }
+
+    let mode = spec
+        .mode
+        .as_deref()
+        .map(CleanupMode::from_str)
+        .transpose()?
+        .unwrap_or(defaults.mode);
+
+    let archive = match &spec.archive {
+        Some(archive) if is_valid_qualified_tname(archive) => Some(archive.clone()),
+        Some(archive) => bail!("Invalid archive target: {archive}"),
+        None => defaults
+            .archive_suffix
+            .as_ref()
+            .map(|suffix| format!("{}{suffix}", spec.name)),
+    };
+
+    let batch = spec.batch.unwrap_or(defaults.batch);
+
+    let timeout = spec
+        .timeout
+        .as_deref()
+        .map(parse_duration)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!(e))?
+        .unwrap_or(defaults.timeout);
+
+    Ok(TableConfig {
+        name: spec.name.clone(),
+        timestamp_column: spec.timestamp_column.clone(),
+        days: spec.days,
+        mode,
+        archive,
+        batch,
+        timeout,
+    })
+}
+
+/// Turn a file config's table rules into validated `TableConfig`s. An
+/// individual bad entry is logged and dropped rather than failing the
+/// whole reload, so one typo can't take down every other table's rule.
+pub fn resolve_table_rules(config: &FileConfig, defaults: &TableConfigDefaults) -> Vec<TableConfig> {
+    config
+        .tables
+        .iter()
+        .filter_map(|spec| match to_table_config(spec, defaults) {
+            Ok(table_config) => Some(table_config),
+            Err(e) => {
+                error!("Skipping invalid table rule {}: {}", spec.name, e);
+                None
+            }
+        })
+        .collect()
+}
@@ -1,3 +1,134 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+
 pub fn is_valid_tname(name: &str) -> bool {
     name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
+
+/// Like [`is_valid_tname`], but also accepts a single `schema.table`
+/// qualifier, for archive targets that live outside the source table's
+/// schema.
+pub fn is_valid_qualified_tname(name: &str) -> bool {
+    match name.split_once('.') {
+        Some((schema, table)) => is_valid_tname(schema) && is_valid_tname(table),
+        None => is_valid_tname(name),
+    }
+}
+
+/// The parsed form of a `pg_get_expr(relpartbound, oid)` result for one
+/// child of a `RANGE`-partitioned parent.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PartitionBound {
+    /// `FOR VALUES FROM (...) TO (<upper>)`, with the upper bound parsed.
+    Range(DateTime<Utc>),
+    /// The `DEFAULT` partition, which must never be dropped by date.
+    Default,
+    /// A bound expression this tool doesn't know how to parse (e.g. a
+    /// list/hash partition, or a multi-column range key).
+    Unrecognized,
+}
+
+/// Parse the `TO (...)` upper bound out of a partition bound expression
+/// such as `FOR VALUES FROM ('2024-01-01') TO ('2024-02-01')`.
+pub fn parse_partition_bound(bound_expr: &str) -> PartitionBound {
+    if bound_expr.trim().eq_ignore_ascii_case("DEFAULT") {
+        return PartitionBound::Default;
+    }
+
+    let Some(to_idx) = bound_expr.find("TO (") else {
+        return PartitionBound::Unrecognized;
+    };
+
+    let rest = &bound_expr[to_idx + "TO (".len()..];
+
+    let Some(end_idx) = rest.find(')') else {
+        return PartitionBound::Unrecognized;
+    };
+
+    let literal = rest[..end_idx].trim().trim_matches('\'');
+
+    match parse_timestamp_literal(literal) {
+        Some(upper_bound) => PartitionBound::Range(upper_bound),
+        None => PartitionBound::Unrecognized,
+    }
+}
+
+fn parse_timestamp_literal(literal: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(literal) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    if let Ok(naive) = NaiveDateTime::parse_from_str(literal, "%Y-%m-%d %H:%M:%S") {
+        return Some(naive.and_utc());
+    }
+
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(literal, "%Y-%m-%d") {
+        return Some(date.and_hms_opt(0, 0, 0)?.and_utc());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_valid_tname_accepts_alphanumeric_and_underscore() {
+        assert!(is_valid_tname("events_2024"));
+        assert!(!is_valid_tname("events-2024"));
+        assert!(!is_valid_tname("events.2024"));
+    }
+
+    #[test]
+    fn is_valid_qualified_tname_accepts_a_single_schema_qualifier() {
+        assert!(is_valid_qualified_tname("audit.events"));
+        assert!(is_valid_qualified_tname("events"));
+        assert!(!is_valid_qualified_tname("audit.events.extra"));
+        assert!(!is_valid_qualified_tname("audit.ev-ents"));
+    }
+
+    #[test]
+    fn parse_partition_bound_parses_date_range_upper_bound() {
+        let bound = parse_partition_bound("FOR VALUES FROM ('2024-01-01') TO ('2024-02-01')");
+        assert_eq!(
+            bound,
+            PartitionBound::Range(chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc())
+        );
+    }
+
+    #[test]
+    fn parse_partition_bound_parses_rfc3339_upper_bound() {
+        let bound = parse_partition_bound("FOR VALUES FROM ('2024-01-01T00:00:00Z') TO ('2024-02-01T00:00:00Z')");
+        assert_eq!(
+            bound,
+            PartitionBound::Range(DateTime::parse_from_rfc3339("2024-02-01T00:00:00Z").unwrap().with_timezone(&Utc))
+        );
+    }
+
+    #[test]
+    fn parse_partition_bound_recognizes_default_partition() {
+        assert_eq!(parse_partition_bound("DEFAULT"), PartitionBound::Default);
+        assert_eq!(parse_partition_bound("  default  "), PartitionBound::Default);
+    }
+
+    #[test]
+    fn parse_partition_bound_treats_maxvalue_as_unrecognized() {
+        assert_eq!(
+            parse_partition_bound("FOR VALUES FROM ('2024-01-01') TO (MAXVALUE)"),
+            PartitionBound::Unrecognized
+        );
+    }
+
+    #[test]
+    fn parse_partition_bound_treats_multi_column_range_as_unrecognized() {
+        assert_eq!(
+            parse_partition_bound("FOR VALUES FROM ('2024-01-01', 1) TO ('2024-02-01', 100)"),
+            PartitionBound::Unrecognized
+        );
+    }
+
+    #[test]
+    fn parse_partition_bound_treats_list_partition_as_unrecognized() {
+        assert_eq!(parse_partition_bound("FOR VALUES IN ('us', 'ca')"), PartitionBound::Unrecognized);
+    }
+}